@@ -1,6 +1,7 @@
 use crate::{
     ControlType, DiscreteIncrement, Fraction, Interval, IntervalMatchResult, MinIsMaxBehavior,
-    Transformation, UnitValue, BASE_EPSILON,
+    SoftSymmetricUnitValue, Transformation, TransformationError, UnitIncrement, UnitValue,
+    BASE_EPSILON,
 };
 
 /// Value coming from a source (e.g. a MIDI source) which is supposed to control something.
@@ -16,6 +17,9 @@ pub enum ControlValue {
     AbsoluteDiscrete(Fraction),
     /// Relative increment (e.g. encoder movement)
     Relative(DiscreteIncrement),
+    /// Relative continuous delta (e.g. touchscreen or trackpad movement), for sources which don't
+    /// have the notion of detents.
+    RelativeContinuous(UnitIncrement),
 }
 
 impl ControlValue {
@@ -24,6 +28,14 @@ impl ControlValue {
         ControlValue::AbsoluteContinuous(UnitValue::new(number))
     }
 
+    /// Convenience method for creating an absolute control value from a signed number (-1.0 to 1.0),
+    /// carried as unit value with 0.5 being the center.
+    pub fn absolute_bipolar(number: f64) -> ControlValue {
+        ControlValue::AbsoluteContinuous(
+            SoftSymmetricUnitValue::new(number).map_to_positive_unit_interval(),
+        )
+    }
+
     /// Convenience method for creating a discrete absolute control value
     pub fn absolute_discrete(actual: u32, max: u32) -> ControlValue {
         ControlValue::AbsoluteDiscrete(Fraction::new(actual, max))
@@ -34,6 +46,11 @@ impl ControlValue {
         ControlValue::Relative(DiscreteIncrement::new(increment))
     }
 
+    /// Convenience method for creating a relative continuous control value
+    pub fn relative_continuous(delta: f64) -> ControlValue {
+        ControlValue::RelativeContinuous(UnitIncrement::new(delta))
+    }
+
     pub fn from_absolute(value: AbsoluteValue) -> ControlValue {
         match value {
             AbsoluteValue::Continuous(v) => Self::AbsoluteContinuous(v),
@@ -71,6 +88,7 @@ impl ControlValue {
         match self {
             ControlValue::AbsoluteContinuous(v) => ControlValue::AbsoluteContinuous(v.inverse()),
             ControlValue::Relative(v) => ControlValue::Relative(v.inverse()),
+            ControlValue::RelativeContinuous(v) => ControlValue::RelativeContinuous(v.inverse()),
             ControlValue::AbsoluteDiscrete(v) => ControlValue::AbsoluteDiscrete(v.inverse()),
         }
     }
@@ -78,7 +96,9 @@ impl ControlValue {
     pub fn to_absolute_continuous(self) -> Result<ControlValue, &'static str> {
         match self {
             ControlValue::AbsoluteContinuous(v) => Ok(ControlValue::AbsoluteContinuous(v)),
-            ControlValue::Relative(_) => Err("relative value can't be normalized"),
+            ControlValue::Relative(_) | ControlValue::RelativeContinuous(_) => {
+                Err("relative value can't be normalized")
+            }
             ControlValue::AbsoluteDiscrete(v) => {
                 Ok(ControlValue::AbsoluteContinuous(v.to_unit_value()))
             }
@@ -90,6 +110,36 @@ impl ControlValue {
             .map(|uv| !uv.is_zero())
             .unwrap_or(false)
     }
+
+    /// Checks if this control value is approximately equal to the given one (e.g. for testing or
+    /// deduplication).
+    ///
+    /// Continuous values are compared with the given tolerance, all others (including values of
+    /// different variants) exactly.
+    pub fn approx_eq(&self, other: &ControlValue, epsilon: f64) -> bool {
+        use ControlValue::*;
+        match (self, other) {
+            (AbsoluteContinuous(v1), AbsoluteContinuous(v2)) => {
+                (v1.get() - v2.get()).abs() <= epsilon
+            }
+            (RelativeContinuous(d1), RelativeContinuous(d2)) => {
+                (d1.get() - d2.get()).abs() <= epsilon
+            }
+            _ => self == other,
+        }
+    }
+}
+
+impl approx::AbsDiffEq for ControlValue {
+    type Epsilon = f64;
+
+    fn default_epsilon() -> f64 {
+        f64::EPSILON
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.approx_eq(other, epsilon)
+    }
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
@@ -261,7 +311,7 @@ impl AbsoluteValue {
         current_target_value: Option<AbsoluteValue>,
         is_discrete_mode: bool,
         additional_input: T::AdditionalInput,
-    ) -> Result<Self, &'static str> {
+    ) -> Result<Self, TransformationError> {
         use AbsoluteValue::*;
         match self {
             Continuous(v) => {
@@ -359,6 +409,19 @@ impl AbsoluteValue {
         }
     }
 
+    /// Like `has_same_effect_as()` but considers continuous values as equal if their distance
+    /// is not greater than the given epsilon.
+    pub fn has_same_effect_as_tolerant(self, other: AbsoluteValue, epsilon: f64) -> bool {
+        if let (AbsoluteValue::Discrete(f1), AbsoluteValue::Discrete(f2)) = (self, other) {
+            f1.actual() == f2.actual()
+        } else {
+            self.to_unit_value()
+                .calc_distance_from(other.to_unit_value())
+                .get()
+                <= epsilon
+        }
+    }
+
     pub fn calc_distance_from(self, rhs: Self) -> Self {
         use AbsoluteValue::*;
         match (self, rhs) {
@@ -402,6 +465,24 @@ mod tests {
     use crate::BASE_EPSILON;
     use approx::*;
 
+    #[test]
+    fn approx_eq() {
+        // Given
+        let v1 = ControlValue::absolute_continuous(0.5);
+        let v2 = ControlValue::absolute_continuous(0.5 + 1e-9);
+        let r1 = ControlValue::relative(-3);
+        let r2 = ControlValue::relative(-3);
+        // When
+        // Then
+        assert!(v1.approx_eq(&v2, BASE_EPSILON));
+        assert!(!v1.approx_eq(&v2, 1e-12));
+        assert!(r1.approx_eq(&r2, BASE_EPSILON));
+        assert!(!r1.approx_eq(&ControlValue::relative(3), BASE_EPSILON));
+        assert!(!v1.approx_eq(&ControlValue::absolute_discrete(1, 2), BASE_EPSILON));
+        assert_abs_diff_eq!(v1, v2, epsilon = BASE_EPSILON);
+        assert_abs_diff_eq!(r1, r2);
+    }
+
     #[test]
     fn normalize_comparison() {
         // Given
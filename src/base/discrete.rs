@@ -63,10 +63,17 @@ impl Sub for DiscreteValue {
 
 /// A discrete number representing a positive or negative increment, never 0 (otherwise it wouldn't
 /// be an increment after all).
+///
+/// All operations are safe for the complete `i32` range. Operations which would exceed it (e.g.
+/// inverting `i32::MIN`) saturate at [`DiscreteIncrement::MAX_SAFE_STEP_COUNT`]. Use
+/// [`DiscreteIncrement::large_step_count_to_unit_increment`] for step counts beyond that.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub struct DiscreteIncrement(i32);
 
 impl DiscreteIncrement {
+    /// Largest step count (in both directions) which is processed without saturation.
+    pub const MAX_SAFE_STEP_COUNT: i32 = i32::MAX;
+
     /// Creates the discrete increment. Panics if the given number is 0.
     pub fn new(increment: i32) -> DiscreteIncrement {
         assert_ne!(increment, 0);
@@ -163,33 +170,39 @@ impl DiscreteIncrement {
         // 2 => 5
         // 7 => 10
         // 8 => 10
-        let positive_increment = self.0.abs() as u32;
-        let min: i32 = interval.min_val().get();
-        let max: i32 = interval.max_val().get();
-        let count: u32 = if min < 0 && max > 0 {
-            (max - min) as u32
+        // Calculating with i64 in order to not overflow with huge intervals
+        let positive_increment = self.to_value().get() as i64;
+        let min = interval.min_val().get() as i64;
+        let max = interval.max_val().get() as i64;
+        let count = if min < 0 && max > 0 {
+            max - min
         } else {
-            (max - min) as u32 + 1
+            max - min + 1
         };
-        let addend: u32 = cmp::min(positive_increment - 1, count - 1);
-        let sum = min + addend as i32;
+        let addend = cmp::min(positive_increment - 1, count - 1);
+        let sum = min + addend;
         let skip_zero_sum = if min < 0 && sum >= 0 { sum + 1 } else { sum };
         let clamped = cmp::min(skip_zero_sum, max);
-        DiscreteIncrement::new(clamped)
+        DiscreteIncrement::new(clamped as i32)
     }
 
     /// Converts this discrete increment into a discrete value thereby "losing" its direction.
     pub fn to_value(self) -> DiscreteValue {
-        DiscreteValue::new(self.0.abs() as u32)
+        DiscreteValue::new(self.0.unsigned_abs())
     }
 
     /// Switches the direction of this increment (makes a positive one negative and vice versa).
+    ///
+    /// `i32::MIN` saturates at `i32::MAX`.
     pub fn inverse(&self) -> DiscreteIncrement {
-        unsafe { DiscreteIncrement::new_unchecked(-self.0) }
+        unsafe { DiscreteIncrement::new_unchecked(self.0.saturating_neg()) }
     }
 
+    /// Returns an increment with the same magnitude but the direction of the given signum.
+    ///
+    /// A magnitude of `i32::MIN` saturates at `i32::MAX`.
     pub fn with_direction(&self, signum: i32) -> DiscreteIncrement {
-        let abs = self.0.abs();
+        let abs = self.0.saturating_abs();
         let inner = if signum >= 0 { abs } else { -abs };
         DiscreteIncrement::new(inner)
     }
@@ -217,9 +230,20 @@ impl DiscreteIncrement {
     /// multiple of the given atomic unit value (= minimum step size) and clamping the result if it
     /// exceeds the unit interval.
     pub fn to_unit_increment(self, atomic_unit_value: UnitValue) -> Option<UnitIncrement> {
-        let positive_large = self.to_value().get() as f64 * atomic_unit_value.get();
+        Self::large_step_count_to_unit_increment(self.0 as i64, atomic_unit_value)
+    }
+
+    /// Like [`Self::to_unit_increment`] but for step counts which don't fit into an `i32` (e.g.
+    /// accumulated steps of targets with millions of steps). Returns `None` if the step count is
+    /// zero or the atomic unit value is zero.
+    pub fn large_step_count_to_unit_increment(
+        step_count: i64,
+        atomic_unit_value: UnitValue,
+    ) -> Option<UnitIncrement> {
+        let positive_large = step_count.unsigned_abs() as f64 * atomic_unit_value.get();
         let unit_value = UnitValue::new(num::clamp(positive_large, 0.0, 1.0));
-        unit_value.to_increment(self.signum())
+        let signum = if step_count >= 0 { 1 } else { -1 };
+        unit_value.to_increment(signum)
     }
 }
 
@@ -246,3 +270,60 @@ impl TryFrom<i32> for DiscreteIncrement {
 pub fn create_discrete_increment_interval(min: i32, max: i32) -> Interval<DiscreteIncrement> {
     Interval::new(DiscreteIncrement::new(min), DiscreteIncrement::new(max))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn i32_boundary() {
+        // Given
+        let max = DiscreteIncrement::new(i32::MAX);
+        let min = DiscreteIncrement::new(i32::MIN);
+        let atomic = UnitValue::new(0.000001);
+        // When
+        // Then
+        assert_eq!(max.to_value(), DiscreteValue::new(i32::MAX as u32));
+        assert_eq!(min.to_value(), DiscreteValue::new(1 << 31));
+        assert_eq!(max.inverse(), DiscreteIncrement::new(-i32::MAX));
+        assert_eq!(min.inverse(), DiscreteIncrement::new(i32::MAX));
+        assert_eq!(min.with_direction(1), DiscreteIncrement::new(i32::MAX));
+        assert_eq!(max.to_unit_increment(atomic), Some(UnitIncrement::new(1.0)));
+        assert_eq!(
+            min.to_unit_increment(atomic),
+            Some(UnitIncrement::new(-1.0))
+        );
+        assert_eq!(
+            min.clamp_to_interval(&create_discrete_increment_interval(i32::MIN, i32::MAX)),
+            DiscreteIncrement::new(-1)
+        );
+        assert_eq!(
+            max.clamp_to_interval(&create_discrete_increment_interval(-4, 100)),
+            DiscreteIncrement::new(100)
+        );
+    }
+
+    #[test]
+    fn large_step_count() {
+        // Given
+        let atomic = UnitValue::new(0.5f64.powi(32));
+        // When
+        // Then
+        assert_eq!(
+            DiscreteIncrement::large_step_count_to_unit_increment(0, atomic),
+            None
+        );
+        assert_eq!(
+            DiscreteIncrement::large_step_count_to_unit_increment(1 << 31, atomic),
+            Some(UnitIncrement::new(0.5))
+        );
+        assert_eq!(
+            DiscreteIncrement::large_step_count_to_unit_increment(-(1 << 33), atomic),
+            Some(UnitIncrement::new(-1.0))
+        );
+        assert_eq!(
+            DiscreteIncrement::large_step_count_to_unit_increment(i64::MIN, atomic),
+            Some(UnitIncrement::new(-1.0))
+        );
+    }
+}
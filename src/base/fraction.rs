@@ -16,6 +16,12 @@ impl Fraction {
         Self { actual, max }
     }
 
+    /// Creates a fraction with the given maximum from the given unit value, rounding to the
+    /// nearest discrete value.
+    pub fn from_unit_value(value: UnitValue, max: u32) -> Self {
+        Self::new(value.to_discrete(max), max)
+    }
+
     pub const fn new_min(max: u32) -> Self {
         Self::new(0, max)
     }
@@ -40,14 +46,23 @@ impl Fraction {
         Self::new(actual, self.max)
     }
 
+    /// Returns a fraction with the same actual value but a different maximum (no rescaling).
     pub fn with_max(&self, max: u32) -> Self {
         Self::new(self.actual, max)
     }
 
+    /// Returns a fraction with the given maximum which represents the same percentage as this one
+    /// (as good as the new resolution allows).
+    pub fn with_max_rescaled(&self, max: u32) -> Self {
+        Self::from_unit_value(self.to_unit_value(), max)
+    }
+
     pub fn with_max_clamped(&self, max: u32) -> Self {
         Self::new(std::cmp::min(self.actual, max), max)
     }
 
+    /// Returns the fraction on the "other side" of the range, e.g. 3/10 => 7/10. An actual value
+    /// greater than the maximum is treated like the maximum.
     pub fn inverse(&self) -> Self {
         Self {
             actual: self.max - self.actual_clamped(),
@@ -55,10 +70,13 @@ impl Fraction {
         }
     }
 
+    /// Returns whether the actual value is 0.
     pub fn is_zero(&self) -> bool {
         self.actual == 0
     }
 
+    /// Converts this fraction to a percentage. An actual value greater than the maximum results in
+    /// 1.0, a maximum of 0 in 0.0.
     pub fn to_unit_value(self) -> UnitValue {
         if self.max == 0 {
             return UnitValue::MIN;
@@ -190,6 +208,61 @@ pub fn full_discrete_interval() -> Interval<u32> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn unit_value_round_trip() {
+        for max in [1, 2, 127, 1000, 16383] {
+            for actual in [0, max / 3, max / 2, max] {
+                // Given
+                let fraction = Fraction::new(actual, max);
+                // When
+                let unit_value = fraction.to_unit_value();
+                // Then
+                assert_eq!(Fraction::from_unit_value(unit_value, max), fraction);
+            }
+        }
+    }
+
+    #[test]
+    fn from_unit_value() {
+        assert_eq!(
+            Fraction::from_unit_value(UnitValue::new(0.5), 127),
+            Fraction::new(64, 127)
+        );
+        assert_eq!(
+            Fraction::from_unit_value(UnitValue::MAX, 0),
+            Fraction::new(0, 0)
+        );
+        assert_eq!(
+            Fraction::from_unit_value(UnitValue::MAX, 10),
+            Fraction::new(10, 10)
+        );
+    }
+
+    #[test]
+    fn actual_greater_than_max() {
+        // Given
+        let fraction = Fraction::new(150, 127);
+        // When
+        // Then
+        assert_eq!(fraction.to_unit_value(), UnitValue::MAX);
+        assert_eq!(fraction.inverse(), Fraction::new(0, 127));
+        assert_eq!(fraction.with_max_rescaled(10), Fraction::new(10, 10));
+        assert!(!fraction.is_zero());
+    }
+
+    #[test]
+    fn with_max_rescaled() {
+        assert_eq!(
+            Fraction::new(5, 10).with_max_rescaled(100),
+            Fraction::new(50, 100)
+        );
+        assert_eq!(
+            Fraction::new(127, 127).with_max_rescaled(16383),
+            Fraction::new(16383, 16383)
+        );
+        assert_eq!(Fraction::new(3, 10).with_max(20), Fraction::new(3, 20));
+    }
+
     #[test]
     fn normalize_subset() {
         // Given
@@ -27,6 +27,12 @@ impl<T: PartialOrd + Copy + Sub + Debug> Interval<T> {
         Ok(Interval { min, max })
     }
 
+    /// Creates an interval from two bounds given in any order (e.g. from user input where the
+    /// range was dragged backwards). In contrast to `new()`, this never panics.
+    pub fn from_unordered(a: T, b: T) -> Interval<T> {
+        Interval::new_auto(a, b)
+    }
+
     pub fn new_auto(bound_1: T, bound_2: T) -> Interval<T> {
         Interval {
             min: if bound_1 <= bound_2 { bound_1 } else { bound_2 },
@@ -140,6 +146,23 @@ impl<T: PartialOrd + Copy + Sub + Debug> Interval<T> {
     }
 }
 
+impl Interval<f64> {
+    /// Linearly maps the given value from this interval to the given destination interval.
+    ///
+    /// The value is clamped to this interval first. If this interval has a span of zero, the
+    /// destination minimum is returned.
+    #[allow(clippy::float_cmp)]
+    pub fn map_value_to(&self, value: f64, dest: &Interval<f64>) -> f64 {
+        let span = self.span();
+        if span == 0.0 {
+            return dest.min;
+        }
+        let clamped_value = num::clamp(value, self.min, self.max);
+        let normalized_value = (clamped_value - self.min) / span;
+        dest.min + normalized_value * dest.span()
+    }
+}
+
 #[derive(Eq, PartialEq, Copy, Clone, Debug)]
 pub enum IntervalMatchResult {
     Between,
@@ -159,3 +182,47 @@ impl IntervalMatchResult {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn map_value_to() {
+        // Given
+        let device_interval = Interval::new(0.0, 100.0);
+        // When
+        // Then
+        assert_eq!(
+            device_interval.map_value_to(50.0, &Interval::new(0.0, 1.0)),
+            0.5
+        );
+        assert_eq!(
+            device_interval.map_value_to(50.0, &Interval::new(20.0, 30.0)),
+            25.0
+        );
+        assert_eq!(
+            device_interval.map_value_to(150.0, &Interval::new(20.0, 30.0)),
+            30.0
+        );
+        assert_eq!(
+            device_interval.map_value_to(-10.0, &Interval::new(20.0, 30.0)),
+            20.0
+        );
+        assert_eq!(
+            Interval::new(5.0, 5.0).map_value_to(5.0, &Interval::new(20.0, 30.0)),
+            20.0
+        );
+    }
+
+    #[test]
+    fn from_unordered() {
+        // Given
+        let interval = Interval::from_unordered(0.8, 0.2);
+        // When
+        // Then
+        assert_eq!(interval, Interval::new(0.2, 0.8));
+        assert_eq!(Interval::from_unordered(0.2, 0.8), interval);
+        assert_eq!(Interval::from_unordered(0.5, 0.5), Interval::new(0.5, 0.5));
+    }
+}
@@ -19,6 +19,9 @@ pub use fraction::*;
 mod interval;
 pub use interval::*;
 
+mod typed_value;
+pub use typed_value::*;
+
 mod ui_util;
 pub use ui_util::*;
 
@@ -0,0 +1,89 @@
+use crate::UnitValue;
+
+/// A normalized value on the source side (what a control element emits or receives as
+/// feedback).
+///
+/// Exists to prevent mixing up source values and target values at the API boundary. Both are unit
+/// values under the hood, but they can't be passed in place of each other:
+///
+/// ```compile_fail
+/// use helgoboss_learn::{SourceValue, TargetValue, UnitValue};
+///
+/// fn expects_source_value(_: SourceValue) {}
+///
+/// let target_value = TargetValue::new(UnitValue::new(0.5));
+/// expects_source_value(target_value);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct SourceValue(UnitValue);
+
+impl SourceValue {
+    pub const fn new(value: UnitValue) -> Self {
+        Self(value)
+    }
+
+    /// Returns the underlying unit value.
+    pub const fn get(&self) -> UnitValue {
+        self.0
+    }
+}
+
+impl From<UnitValue> for SourceValue {
+    fn from(value: UnitValue) -> Self {
+        Self(value)
+    }
+}
+
+impl From<SourceValue> for UnitValue {
+    fn from(value: SourceValue) -> Self {
+        value.0
+    }
+}
+
+/// A normalized value on the target side (what is sent to a target or reported by it).
+///
+/// The counterpart of [`SourceValue`]:
+///
+/// ```compile_fail
+/// use helgoboss_learn::{SourceValue, TargetValue, UnitValue};
+///
+/// fn expects_target_value(_: TargetValue) {}
+///
+/// let source_value = SourceValue::new(UnitValue::new(0.5));
+/// expects_target_value(source_value);
+/// ```
+///
+/// Explicit conversions are always possible:
+///
+/// ```
+/// use helgoboss_learn::{SourceValue, TargetValue, UnitValue};
+///
+/// let source_value = SourceValue::new(UnitValue::new(0.5));
+/// let target_value = TargetValue::from(UnitValue::from(source_value));
+/// assert_eq!(target_value.get(), UnitValue::new(0.5));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct TargetValue(UnitValue);
+
+impl TargetValue {
+    pub const fn new(value: UnitValue) -> Self {
+        Self(value)
+    }
+
+    /// Returns the underlying unit value.
+    pub const fn get(&self) -> UnitValue {
+        self.0
+    }
+}
+
+impl From<UnitValue> for TargetValue {
+    fn from(value: UnitValue) -> Self {
+        Self(value)
+    }
+}
+
+impl From<TargetValue> for UnitValue {
+    fn from(value: TargetValue) -> Self {
+        value.0
+    }
+}
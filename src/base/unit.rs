@@ -127,11 +127,15 @@ impl UnitValue {
     /// 1.0
     pub const MAX: UnitValue = UnitValue(1.0);
 
+    /// Returns whether the given number is within the positive unit interval (NaN is not).
     pub fn is_valid(number: f64) -> bool {
         (0.0..=1.0).contains(&number)
     }
 
     /// Creates the unit value. Panics if the given number is not within the positive unit interval.
+    ///
+    /// Use [`Self::new_checked`] for numbers which might be out of range (e.g. user input) or
+    /// [`Self::new_clamped`] if out-of-range numbers should be corrected silently.
     pub fn new(number: f64) -> UnitValue {
         assert!(
             Self::is_valid(number),
@@ -141,6 +145,14 @@ impl UnitValue {
         UnitValue(number)
     }
 
+    /// Creates the unit value. Returns an error if the given number is not within the positive unit
+    /// interval.
+    pub fn new_checked(number: f64) -> Result<UnitValue, &'static str> {
+        UnitValue::try_from(number)
+    }
+
+    /// Creates the unit value, clamping the given number to the positive unit interval. NaN
+    /// results in 0.0.
     pub fn new_clamped(number: f64) -> UnitValue {
         let actual_number = if number > 1.0 {
             1.0
@@ -169,6 +181,19 @@ impl UnitValue {
         UnitValue(number)
     }
 
+    /// Creates a unit value from the given percentage (0.0 - 100.0).
+    ///
+    /// The percentage is rounded to 2 fractional digits (same as `to_percent()`) and clamped to
+    /// the unit interval, so values entered in percent survive a round trip unchanged.
+    pub fn from_percent(percent: f64) -> UnitValue {
+        UnitValue::new_clamped(round_percent(percent) / 100.0)
+    }
+
+    /// Returns this value as percentage (0.0 - 100.0), rounded to 2 fractional digits.
+    pub fn to_percent(&self) -> f64 {
+        round_percent(self.0 * 100.0)
+    }
+
     // TODO Maybe we should rather implement From<UnitValue> for f64? Same with other newtypes.
     /// Returns the underlying number.
     pub fn get(&self) -> f64 {
@@ -183,6 +208,11 @@ impl UnitValue {
         SoftSymmetricUnitValue::new((self.0 * 2.0) - 1.0)
     }
 
+    /// Checks if this value is within the given interval.
+    ///
+    /// **Attention:** This is strict at the interval bounds. Consider using
+    /// [`Interval::value_matches_tolerant`] for values which could be affected by numerical
+    /// inaccuracies (e.g. after a float conversion).
     pub fn is_within_interval(&self, interval: &Interval<UnitValue>) -> bool {
         interval.contains(*self)
     }
@@ -343,6 +373,65 @@ impl UnitValue {
         }
     }
 
+    /// Like [`Self::add_rotating`] but calculates in whole grid steps if both this value and the
+    /// increment are aligned to the grid defined by the given interval size.
+    ///
+    /// Bounds which are not on the grid are snapped inwards to it, so rotation always lands exactly
+    /// on a grid-aligned bound. Bounds which are already on the grid (e.g. because the caller
+    /// snapped them to the nearest grid value) are used as they are.
+    /// Because the result is derived from integer step counts instead of summing up floating
+    /// point values, it doesn't drift off the grid over many rotations. Falls back to
+    /// [`Self::add_rotating`] if something is not aligned to the grid.
+    pub fn add_rotating_on_grid(
+        &self,
+        increment: UnitIncrement,
+        interval: &Interval<UnitValue>,
+        grid_interval_size: UnitValue,
+        epsilon: f64,
+    ) -> UnitValue {
+        let fallback = || self.add_rotating(increment, interval, epsilon);
+        if grid_interval_size.is_zero() {
+            return fallback();
+        }
+        let grid = grid_interval_size.0;
+        let grid_epsilon = epsilon / grid;
+        let to_grid_index = |v: f64| -> Option<f64> {
+            let raw_index = v / grid;
+            let index = raw_index.round();
+            if (raw_index - index).abs() <= grid_epsilon {
+                Some(index)
+            } else {
+                None
+            }
+        };
+        let (index, step_count) = match (to_grid_index(self.0), to_grid_index(increment.get())) {
+            (Some(i), Some(s)) => (i, s),
+            _ => return fallback(),
+        };
+        let min_index = (interval.min_val().0 / grid - grid_epsilon).ceil();
+        let max_index = (interval.max_val().0 / grid + grid_epsilon).floor();
+        if min_index > max_index {
+            return fallback();
+        }
+        let new_index = if index < min_index || index > max_index {
+            if increment.is_positive() {
+                min_index
+            } else {
+                max_index
+            }
+        } else {
+            let sum = index + step_count;
+            if sum > max_index {
+                min_index
+            } else if sum < min_index {
+                max_index
+            } else {
+                sum
+            }
+        };
+        UnitValue::new_clamped(new_index * grid)
+    }
+
     /// Adds the given increment. If the result doesn't fit into the given interval anymore, it just
     /// snaps to the bound of that interval. If this unit value is not within the given interval in
     /// the first place, it returns the closest interval bound instead of doing the addition.
@@ -442,6 +531,12 @@ impl std::str::FromStr for UnitValue {
 }
 
 impl Interval<UnitValue> {
+    /// Creates an interval from the given percentages (0.0 - 100.0), rounded and clamped as in
+    /// `UnitValue::from_percent()`. The bounds are ordered automatically.
+    pub fn from_percent_bounds(min: f64, max: f64) -> Interval<UnitValue> {
+        Interval::new_auto(UnitValue::from_percent(min), UnitValue::from_percent(max))
+    }
+
     /// Returns the value which is exactly in the middle between the interval bounds.
     pub fn center(&self) -> UnitValue {
         unsafe { UnitValue::new_unchecked((self.min_val() + self.max_val()) / 2.0) }
@@ -456,6 +551,31 @@ impl Interval<UnitValue> {
     pub fn inverse(&self) -> Interval<UnitValue> {
         Interval::new(self.max_val().inverse(), self.min_val().inverse())
     }
+
+    /// Scales the span of this interval by the given factor around its center. The resulting
+    /// bounds are clamped to the unit interval. Negative factors are treated like 0.0.
+    pub fn scaled(&self, factor: f64) -> Interval<UnitValue> {
+        let center = self.center().get();
+        let half_span = self.span() / 2.0 * factor.max(0.0);
+        Interval::new(
+            UnitValue::new_clamped(center - half_span),
+            UnitValue::new_clamped(center + half_span),
+        )
+    }
+
+    /// Translates both bounds of this interval by the given offset. The resulting bounds are
+    /// clamped to the unit interval (which means the span can shrink).
+    pub fn shifted(&self, offset: f64) -> Interval<UnitValue> {
+        Interval::new(
+            UnitValue::new_clamped(self.min_val().get() + offset),
+            UnitValue::new_clamped(self.max_val().get() + offset),
+        )
+    }
+}
+
+/// Rounds the given percentage to 2 fractional digits.
+fn round_percent(percent: f64) -> f64 {
+    (percent * 100.0).round() / 100.0
 }
 
 /// Convenience method for getting the complete unit interval.
@@ -474,6 +594,8 @@ pub fn create_unit_value_interval(min: f64, max: f64) -> Interval<UnitValue> {
 pub struct UnitIncrement(f64);
 
 impl UnitIncrement {
+    /// Returns whether the given number is a valid unit increment, that is, not 0.0 and within
+    /// the positive or negative unit interval.
     pub fn is_valid(number: f64) -> bool {
         number != 0.0 && (-1.0..=1.0).contains(&number)
     }
@@ -542,7 +664,29 @@ impl UnitIncrement {
         }
     }
 
-    /// Converts this unit increment into a unit value thereby "losing" its direction.
+    /// Returns the increment with the opposite direction.
+    pub fn inverse(&self) -> UnitIncrement {
+        UnitIncrement(-self.0)
+    }
+
+    /// Returns the increment which leads from the given start value to the given end value.
+    /// Returns `None` if both values are equal.
+    ///
+    /// Together with [`UnitValue::add_clamping`] this allows building custom absolute-increment
+    /// logic on top of absolute values.
+    #[allow(clippy::float_cmp)]
+    pub fn between(from: UnitValue, to: UnitValue) -> Option<UnitIncrement> {
+        let delta = to.get() - from.get();
+        if delta == 0.0 {
+            return None;
+        }
+        Some(unsafe { UnitIncrement::new_unchecked(delta) })
+    }
+
+    /// Converts this unit increment into a unit value thereby "losing" its direction. In other
+    /// words, returns its magnitude.
+    ///
+    /// Use [`UnitValue::to_increment`] to go back.
     pub fn to_value(self) -> UnitValue {
         unsafe { UnitValue::new_unchecked(self.0.abs()) }
     }
@@ -557,6 +701,177 @@ impl UnitIncrement {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::BASE_EPSILON;
+    use approx::*;
+
+    #[test]
+    fn percent() {
+        assert_eq!(UnitValue::from_percent(20.0), UnitValue::new(0.2));
+        assert_eq!(UnitValue::new(0.2).to_percent(), 20.0);
+        assert_eq!(UnitValue::new(0.199999999).to_percent(), 20.0);
+        assert_eq!(UnitValue::from_percent(33.333).to_percent(), 33.33);
+        assert_eq!(UnitValue::new(1.0 / 3.0).to_percent(), 33.33);
+        assert_eq!(
+            UnitValue::from_percent(UnitValue::new(1.0 / 3.0).to_percent()),
+            UnitValue::from_percent(33.333)
+        );
+        assert_eq!(UnitValue::from_percent(-5.0), UnitValue::MIN);
+        assert_eq!(UnitValue::from_percent(150.0), UnitValue::MAX);
+    }
+
+    #[test]
+    fn checked_and_clamped() {
+        assert_eq!(UnitValue::new_checked(0.5), Ok(UnitValue::new(0.5)));
+        assert_eq!(UnitValue::new_checked(0.0), Ok(UnitValue::MIN));
+        assert_eq!(UnitValue::new_checked(1.0), Ok(UnitValue::MAX));
+        assert!(UnitValue::new_checked(1.5).is_err());
+        assert!(UnitValue::new_checked(-0.1).is_err());
+        assert!(UnitValue::new_checked(f64::NAN).is_err());
+        assert_eq!(UnitValue::new_clamped(0.5), UnitValue::new(0.5));
+        assert_eq!(UnitValue::new_clamped(1.5), UnitValue::MAX);
+        assert_eq!(UnitValue::new_clamped(-0.1), UnitValue::MIN);
+        assert_eq!(UnitValue::new_clamped(f64::NAN), UnitValue::MIN);
+        assert!(std::panic::catch_unwind(|| UnitValue::new(1.5)).is_err());
+        assert!(std::panic::catch_unwind(|| UnitValue::new(-0.1)).is_err());
+    }
+
+    #[test]
+    fn percent_bounds() {
+        // Given
+        let interval = Interval::from_percent_bounds(80.0, 20.0);
+        // When
+        // Then
+        assert_eq!(interval.min_val().to_percent(), 20.0);
+        assert_eq!(interval.max_val().to_percent(), 80.0);
+        assert_eq!(
+            Interval::from_percent_bounds(0.0, 100.0),
+            full_unit_interval()
+        );
+    }
+
+    #[test]
+    fn scaled() {
+        // Given
+        let interval = create_unit_value_interval(0.3, 0.7);
+        // When
+        let scaled_2 = interval.scaled(2.0);
+        let scaled_3 = interval.scaled(3.0);
+        let scaled_half = interval.scaled(0.5);
+        // Then
+        assert_eq!(scaled_2.min_val().to_percent(), 10.0);
+        assert_eq!(scaled_2.max_val().to_percent(), 90.0);
+        assert_eq!(scaled_3, full_unit_interval());
+        assert_eq!(scaled_half.min_val().to_percent(), 40.0);
+        assert_eq!(scaled_half.max_val().to_percent(), 60.0);
+    }
+
+    #[test]
+    fn shifted() {
+        // Given
+        let interval = create_unit_value_interval(0.3, 0.7);
+        // When
+        let shifted_up = interval.shifted(0.5);
+        let shifted_down = interval.shifted(-0.2);
+        // Then
+        assert_eq!(shifted_up.min_val().to_percent(), 80.0);
+        assert_eq!(shifted_up.max_val(), UnitValue::MAX);
+        assert_eq!(shifted_down.min_val().to_percent(), 10.0);
+        assert_eq!(shifted_down.max_val().to_percent(), 50.0);
+    }
+
+    #[test]
+    fn unit_increment_round_trip() {
+        // Given
+        let from = UnitValue::new(0.3);
+        let to = UnitValue::new(0.55);
+        // When
+        let up = UnitIncrement::between(from, to).unwrap();
+        let down = UnitIncrement::between(to, from).unwrap();
+        // Then
+        assert!(UnitIncrement::between(from, from).is_none());
+        assert!(up.is_positive());
+        assert_eq!(down.signum(), -1);
+        assert_eq!(up.inverse(), down);
+        assert_abs_diff_eq!(up.to_value().get(), 0.25, epsilon = BASE_EPSILON);
+        assert_eq!(up.to_value().to_increment(up.signum()), Some(up));
+        assert_eq!(down.to_value().to_increment(down.signum()), Some(down));
+        assert_eq!(UnitValue::MIN.to_increment(1), None);
+        assert_abs_diff_eq!(
+            from.add_clamping(up, &full_unit_interval(), BASE_EPSILON)
+                .get(),
+            to.get(),
+            epsilon = BASE_EPSILON
+        );
+        assert_abs_diff_eq!(
+            to.add_clamping(down, &full_unit_interval(), BASE_EPSILON)
+                .get(),
+            from.get(),
+            epsilon = BASE_EPSILON
+        );
+    }
+
+    #[test]
+    fn add_clamping_at_bounds() {
+        // Given
+        let interval = create_unit_value_interval(0.2, 0.8);
+        let up = UnitIncrement::new(0.1);
+        let down = up.inverse();
+        // When
+        // Then
+        assert_eq!(
+            UnitValue::new(0.8).add_clamping(up, &interval, BASE_EPSILON),
+            UnitValue::new(0.8)
+        );
+        assert_eq!(
+            UnitValue::new(0.75).add_clamping(up, &interval, BASE_EPSILON),
+            UnitValue::new(0.8)
+        );
+        assert_eq!(
+            UnitValue::new(0.2).add_clamping(down, &interval, BASE_EPSILON),
+            UnitValue::new(0.2)
+        );
+        // Outside of interval
+        assert_eq!(
+            UnitValue::new(0.1).add_clamping(down, &interval, BASE_EPSILON),
+            UnitValue::new(0.2)
+        );
+        assert_eq!(
+            UnitValue::new(0.9).add_clamping(up, &interval, BASE_EPSILON),
+            UnitValue::new(0.8)
+        );
+    }
+
+    #[test]
+    fn add_rotating_at_bounds() {
+        // Given
+        let interval = create_unit_value_interval(0.2, 0.8);
+        let up = UnitIncrement::new(0.1);
+        let down = up.inverse();
+        // When
+        // Then
+        assert_eq!(
+            UnitValue::new(0.8).add_rotating(up, &interval, BASE_EPSILON),
+            UnitValue::new(0.2)
+        );
+        assert_eq!(
+            UnitValue::new(0.2).add_rotating(down, &interval, BASE_EPSILON),
+            UnitValue::new(0.8)
+        );
+        // Slightly above max because of numerical inaccuracies
+        assert_eq!(
+            UnitValue::new(0.8 + 1e-12).add_rotating(up, &interval, BASE_EPSILON),
+            UnitValue::new(0.2)
+        );
+        // Outside of interval
+        assert_eq!(
+            UnitValue::new(0.1).add_rotating(up, &interval, BASE_EPSILON),
+            UnitValue::new(0.2)
+        );
+        assert_eq!(
+            UnitValue::new(0.9).add_rotating(down, &interval, BASE_EPSILON),
+            UnitValue::new(0.8)
+        );
+    }
 
     #[test]
     fn map_from_unit_interval_to_discrete_increment() {
@@ -148,6 +148,134 @@ impl Default for EncoderUsage {
     }
 }
 
+/// Restricts the direction in which control may change the target value.
+#[derive(
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Hash,
+    Debug,
+    IntoEnumIterator,
+    TryFromPrimitive,
+    IntoPrimitive,
+    Display,
+)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(usize)]
+pub enum DirectionFilter {
+    #[cfg_attr(feature = "serde", serde(rename = "both"))]
+    #[display(fmt = "Increase & decrease")]
+    Both,
+    #[cfg_attr(feature = "serde", serde(rename = "increase-only"))]
+    #[display(fmt = "Increase only")]
+    IncreaseOnly,
+    #[cfg_attr(feature = "serde", serde(rename = "decrease-only"))]
+    #[display(fmt = "Decrease only")]
+    DecreaseOnly,
+}
+
+impl Default for DirectionFilter {
+    fn default() -> Self {
+        DirectionFilter::Both
+    }
+}
+
+impl DirectionFilter {
+    /// Returns whether changing the target value from `current` to `desired` is allowed.
+    pub fn allows(self, current: UnitValue, desired: UnitValue) -> bool {
+        use DirectionFilter::*;
+        match self {
+            Both => true,
+            IncreaseOnly => desired >= current,
+            DecreaseOnly => desired <= current,
+        }
+    }
+
+    /// Returns whether a relative change in the given direction is allowed.
+    pub fn allows_increment(self, is_positive: bool) -> bool {
+        use DirectionFilter::*;
+        match self {
+            Both => true,
+            IncreaseOnly => is_positive,
+            DecreaseOnly => !is_positive,
+        }
+    }
+}
+
+/// Determines how absolute continuous control values are interpreted.
+#[derive(
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Hash,
+    Debug,
+    IntoEnumIterator,
+    TryFromPrimitive,
+    IntoPrimitive,
+    Display,
+)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(usize)]
+pub enum SourceValueEncoding {
+    /// The control value goes from the source interval minimum to its maximum.
+    #[cfg_attr(feature = "serde", serde(rename = "unipolar"))]
+    #[display(fmt = "Unipolar")]
+    Unipolar,
+    /// The control value is a signed value (-1.0 to 1.0) centered around 0.0, e.g. from pitch
+    /// bend or a joystick. By convention, it's carried as unit value with 0.5 being the center
+    /// (see [`ControlValue::absolute_bipolar`](crate::ControlValue::absolute_bipolar)). The source interval applies to the distance from
+    /// the center in both directions.
+    #[cfg_attr(feature = "serde", serde(rename = "bipolar"))]
+    #[display(fmt = "Bipolar")]
+    Bipolar,
+}
+
+impl Default for SourceValueEncoding {
+    fn default() -> Self {
+        SourceValueEncoding::Unipolar
+    }
+}
+
+/// Determines what relative control does if the target value is at a bound of the target value
+/// interval and the increment points beyond that bound.
+#[derive(
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Hash,
+    Debug,
+    IntoEnumIterator,
+    TryFromPrimitive,
+    IntoPrimitive,
+    Display,
+)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(usize)]
+pub enum BoundBehavior {
+    /// Stays at the bound and doesn't hit the target.
+    #[cfg_attr(feature = "serde", serde(rename = "stop"))]
+    #[display(fmt = "Stop")]
+    Stop,
+    /// Stays at the bound but hits the target with the bound value anyway (e.g. for targets which
+    /// should be notified of each increment).
+    #[cfg_attr(feature = "serde", serde(rename = "hold"))]
+    #[display(fmt = "Hold")]
+    Hold,
+    /// Jumps to the opposite bound (same as `rotate`).
+    #[cfg_attr(feature = "serde", serde(rename = "wrap"))]
+    #[display(fmt = "Wrap")]
+    Wrap,
+}
+
+impl Default for BoundBehavior {
+    fn default() -> Self {
+        BoundBehavior::Stop
+    }
+}
+
 #[derive(
     Copy,
     Clone,
@@ -178,6 +306,9 @@ pub enum FireMode {
     #[cfg_attr(feature = "serde", serde(rename = "double"))]
     #[display(fmt = "Fire on double press")]
     OnDoublePress,
+    #[cfg_attr(feature = "serde", serde(rename = "confirm"))]
+    #[display(fmt = "Fire on release after hold (cancel if released early)")]
+    HoldToConfirm,
 }
 
 impl Default for FireMode {
@@ -213,6 +344,9 @@ pub enum TakeoverMode {
     #[cfg_attr(feature = "serde", serde(rename = "valueScaling"))]
     #[display(fmt = "Catch up")]
     CatchUp,
+    #[cfg_attr(feature = "serde", serde(rename = "easeIn"))]
+    #[display(fmt = "Ease in")]
+    EaseIn,
 }
 
 impl Default for TakeoverMode {
@@ -271,3 +405,42 @@ impl GroupInteraction {
         )
     }
 }
+
+/// Simple built-in curve for shaping normalized values without having to write a transformation.
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ResponseCurve {
+    #[cfg_attr(feature = "serde", serde(rename = "linear"))]
+    Linear,
+    /// Raises the value to the power of the given exponent. Exponents greater than 1.0 make the
+    /// lower part of the range more fine-grained. Non-positive exponents behave linearly.
+    #[cfg_attr(feature = "serde", serde(rename = "exponential"))]
+    Exponential(f64),
+}
+
+impl Default for ResponseCurve {
+    fn default() -> Self {
+        Self::Linear
+    }
+}
+
+impl ResponseCurve {
+    pub fn apply(self, value: UnitValue) -> UnitValue {
+        use ResponseCurve::*;
+        match self {
+            Exponential(exponent) if exponent > 0.0 => {
+                UnitValue::new_clamped(value.get().powf(exponent))
+            }
+            _ => value,
+        }
+    }
+
+    /// Returns the curve which undoes this curve.
+    pub fn inverse(self) -> Self {
+        use ResponseCurve::*;
+        match self {
+            Exponential(exponent) if exponent > 0.0 => Exponential(1.0 / exponent),
+            _ => Linear,
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use crate::{UnitValue, FEEDBACK_EPSILON};
+
+/// Suppresses redundant consecutive feedback values.
+///
+/// Useful for slow devices (e.g. LCD text strips) which shouldn't be bombarded with the same
+/// value over and over again.
+#[derive(Clone, Debug)]
+pub struct FeedbackDeduper {
+    // Configuration data (stays constant)
+    epsilon: f64,
+    // Runtime data (changes during usage)
+    last_emitted_value: Option<UnitValue>,
+}
+
+impl Default for FeedbackDeduper {
+    fn default() -> Self {
+        Self::new(FEEDBACK_EPSILON)
+    }
+}
+
+impl FeedbackDeduper {
+    pub fn new(epsilon: f64) -> Self {
+        Self {
+            epsilon,
+            last_emitted_value: None,
+        }
+    }
+
+    /// Returns the given value if it differs from the last emitted value (considering the
+    /// epsilon), otherwise `None`.
+    pub fn dedupe(&mut self, value: UnitValue) -> Option<UnitValue> {
+        if let Some(last_emitted_value) = self.last_emitted_value {
+            if (value.get() - last_emitted_value.get()).abs() <= self.epsilon {
+                return None;
+            }
+        }
+        self.last_emitted_value = Some(value);
+        Some(value)
+    }
+
+    /// Forgets the last emitted value, so the next value is emitted in any case.
+    pub fn reset(&mut self) {
+        self.last_emitted_value = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repeated_values() {
+        // Given
+        let mut deduper = FeedbackDeduper::default();
+        // When
+        let emitted: Vec<_> = (0..5)
+            .filter_map(|_| deduper.dedupe(UnitValue::new(0.5)))
+            .collect();
+        // Then
+        assert_eq!(emitted, vec![UnitValue::new(0.5)]);
+    }
+
+    #[test]
+    fn changed_values() {
+        // Given
+        let mut deduper = FeedbackDeduper::new(0.01);
+        // When
+        // Then
+        assert_eq!(
+            deduper.dedupe(UnitValue::new(0.5)),
+            Some(UnitValue::new(0.5))
+        );
+        assert_eq!(deduper.dedupe(UnitValue::new(0.505)), None);
+        assert_eq!(
+            deduper.dedupe(UnitValue::new(0.6)),
+            Some(UnitValue::new(0.6))
+        );
+        assert_eq!(
+            deduper.dedupe(UnitValue::new(0.5)),
+            Some(UnitValue::new(0.5))
+        );
+    }
+
+    #[test]
+    fn reset() {
+        // Given
+        let mut deduper = FeedbackDeduper::default();
+        // When
+        deduper.dedupe(UnitValue::new(0.5));
+        deduper.reset();
+        // Then
+        assert_eq!(
+            deduper.dedupe(UnitValue::new(0.5)),
+            Some(UnitValue::new(0.5))
+        );
+    }
+}
@@ -6,10 +6,14 @@ mod mode_struct;
 pub use mode_struct::*;
 mod mode_applicability;
 pub use mode_applicability::*;
+mod mode_validation;
+pub use mode_validation::*;
 mod transformation;
 pub use transformation::*;
 mod press_duration_processor;
 pub use press_duration_processor::*;
+mod feedback_deduper;
+pub use feedback_deduper::*;
 mod value_sequence;
 pub use value_sequence::*;
 
@@ -214,6 +214,9 @@ pub fn check_mode_applicability(input: ModeApplicabilityCheckInput) -> ModeAppli
                                 "If enabled, decreases the target value on press instead of increasing it.",
                             ),
                             crate::AbsoluteMode::ToggleButton => MakesNoSenseUseDefault,
+                            crate::AbsoluteMode::Trigger => MakesSense(
+                                "If enabled, inverts the velocity-dependent target value which is set when pressed.",
+                            ),
                         }
                     }
                     RangeControl | Relative => {
@@ -604,6 +607,15 @@ pub fn check_mode_applicability(input: ModeApplicabilityCheckInput) -> ModeAppli
                     OnDoublePress => MakesSense(
                         "Reacts to double button presses only (like a mouse double-click).",
                     ),
+                    HoldToConfirm => {
+                        if input.source_character == DetailedSourceCharacter::PressOnlyButton {
+                            MakesNoSenseParentTakesCareOfDefault
+                        } else {
+                            MakesSense(
+                                "Fires on release only if the button was held at least min and at most max (if > 0 ms). Releasing too early cancels, nothing is fired.",
+                            )
+                        }
+                    }
                 }
             }
         }
@@ -712,6 +724,9 @@ pub fn check_mode_applicability(input: ModeApplicabilityCheckInput) -> ModeAppli
                             ToggleButton => MakesSense(
                                 "Switches the target value between its minimum and maximum on each button press.",
                             ),
+                            Trigger => MakesSense(
+                                "When pressing the button, sets the target value to a velocity-dependent value. Sets it back to the rest value when releasing it.",
+                            ),
                         }
                     }
                     RangeControl | Relative => {
@@ -0,0 +1,226 @@
+use crate::{
+    default_step_count_interval, default_step_size_interval, AbsoluteMode, ControlType, Mode,
+    Transformation,
+};
+use derive_more::Display;
+
+/// Machine-readable code of a mismatch between mode settings and the character of a target.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Display)]
+pub enum ModeIssueCode {
+    #[display(fmt = "Step size is ignored for discrete targets")]
+    StepSizeIgnoredForDiscreteTarget,
+    #[display(fmt = "Step count is ignored for continuous targets")]
+    StepCountIgnoredForContinuousTarget,
+    #[display(fmt = "Toggle mode needs a target which reports its current value")]
+    ToggleWithoutCurrentValue,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct ModeIssue {
+    pub code: ModeIssueCode,
+    /// Human-readable suggestion how to fix the issue, suitable for inline warnings.
+    pub suggested_fix: &'static str,
+}
+
+impl ModeIssue {
+    fn new(code: ModeIssueCode, suggested_fix: &'static str) -> Self {
+        Self {
+            code,
+            suggested_fix,
+        }
+    }
+}
+
+impl<T: Transformation> Mode<T> {
+    /// Checks if the mode settings make sense for a target with the given control type.
+    ///
+    /// Returns an empty vector if no issues have been found.
+    pub fn validate_for(&self, control_type: ControlType) -> Vec<ModeIssue> {
+        use ControlType::*;
+        let settings = self.settings();
+        let mut issues = vec![];
+        let step_size_configured = settings.step_size_interval != default_step_size_interval()
+            || settings.step_size_interval_down.is_some();
+        let step_count_configured = settings.step_count_interval != default_step_count_interval();
+        let converts_relative_to_absolute = self.converts_relative_to_absolute();
+        match control_type {
+            AbsoluteDiscrete { .. } => {
+                if step_size_configured && !converts_relative_to_absolute {
+                    issues.push(ModeIssue::new(
+                        ModeIssueCode::StepSizeIgnoredForDiscreteTarget,
+                        "Step size is meaningless for a discrete target, use step count instead",
+                    ));
+                }
+            }
+            AbsoluteContinuousRoundable { .. } if !step_size_configured => {
+                // Without explicitly configured step size, a roundable target is controlled like
+                // a discrete one (using step counts)
+            }
+            AbsoluteContinuous
+            | AbsoluteContinuousRoundable { .. }
+            | AbsoluteContinuousRetriggerable => {
+                if step_count_configured
+                    && !converts_relative_to_absolute
+                    && !settings.continuous_step_count
+                {
+                    issues.push(ModeIssue::new(
+                        ModeIssueCode::StepCountIgnoredForContinuousTarget,
+                        "Step count is meaningless for a continuous target, use step size instead",
+                    ));
+                }
+            }
+            Relative | VirtualMulti | VirtualButton => {}
+        }
+        if settings.absolute_mode == AbsoluteMode::ToggleButton
+            && matches!(control_type, Relative | VirtualMulti | VirtualButton)
+        {
+            issues.push(ModeIssue::new(
+                ModeIssueCode::ToggleWithoutCurrentValue,
+                "Use absolute mode \"Normal\" because this target doesn't report a current value",
+            ));
+        }
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mode::test_util::TestTransformation;
+    use crate::{
+        create_discrete_increment_interval, create_unit_value_interval, ModeSettings, UnitValue,
+    };
+
+    #[test]
+    fn step_size_against_discrete_target() {
+        // Given
+        let mode: Mode<TestTransformation> = Mode::new(ModeSettings {
+            step_size_interval: create_unit_value_interval(0.05, 0.1),
+            ..Default::default()
+        });
+        // When
+        let issues = mode.validate_for(ControlType::AbsoluteDiscrete {
+            atomic_step_size: UnitValue::new(0.1),
+        });
+        // Then
+        assert_eq!(issues.len(), 1);
+        assert_eq!(
+            issues[0].code,
+            ModeIssueCode::StepSizeIgnoredForDiscreteTarget
+        );
+        assert!(mode
+            .validate_for(ControlType::AbsoluteContinuous)
+            .is_empty());
+    }
+
+    #[test]
+    fn step_count_against_continuous_target() {
+        // Given
+        let mode: Mode<TestTransformation> = Mode::new(ModeSettings {
+            step_count_interval: create_discrete_increment_interval(1, 5),
+            ..Default::default()
+        });
+        // When
+        let issues = mode.validate_for(ControlType::AbsoluteContinuous);
+        // Then
+        assert_eq!(issues.len(), 1);
+        assert_eq!(
+            issues[0].code,
+            ModeIssueCode::StepCountIgnoredForContinuousTarget
+        );
+        assert!(mode
+            .validate_for(ControlType::AbsoluteDiscrete {
+                atomic_step_size: UnitValue::new(0.1),
+            })
+            .is_empty());
+    }
+
+    #[test]
+    fn step_count_against_continuous_target_with_continuous_step_count() {
+        // Given
+        let mode: Mode<TestTransformation> = Mode::new(ModeSettings {
+            step_count_interval: create_discrete_increment_interval(1, 5),
+            continuous_step_count: true,
+            ..Default::default()
+        });
+        // When
+        // Then
+        assert!(mode
+            .validate_for(ControlType::AbsoluteContinuous)
+            .is_empty());
+    }
+
+    #[test]
+    fn step_count_against_roundable_target() {
+        // Given
+        let step_count_mode: Mode<TestTransformation> = Mode::new(ModeSettings {
+            step_count_interval: create_discrete_increment_interval(1, 5),
+            ..Default::default()
+        });
+        let step_count_and_size_mode: Mode<TestTransformation> = Mode::new(ModeSettings {
+            step_count_interval: create_discrete_increment_interval(1, 5),
+            step_size_interval: create_unit_value_interval(0.05, 0.1),
+            ..Default::default()
+        });
+        let control_type = ControlType::AbsoluteContinuousRoundable {
+            rounding_step_size: UnitValue::new(0.1),
+        };
+        // When
+        let issues = step_count_and_size_mode.validate_for(control_type);
+        // Then
+        assert!(step_count_mode.validate_for(control_type).is_empty());
+        assert_eq!(issues.len(), 1);
+        assert_eq!(
+            issues[0].code,
+            ModeIssueCode::StepCountIgnoredForContinuousTarget
+        );
+    }
+
+    #[test]
+    fn step_size_against_discrete_target_with_hybrid_nudge() {
+        // Given
+        let mode: Mode<TestTransformation> = Mode::new(ModeSettings {
+            step_size_interval: create_unit_value_interval(0.05, 0.1),
+            hybrid_nudge: true,
+            ..Default::default()
+        });
+        // When
+        // Then
+        assert!(mode
+            .validate_for(ControlType::AbsoluteDiscrete {
+                atomic_step_size: UnitValue::new(0.1),
+            })
+            .is_empty());
+    }
+
+    #[test]
+    fn toggle_against_virtual_target() {
+        // Given
+        let mode: Mode<TestTransformation> = Mode::new(ModeSettings {
+            absolute_mode: AbsoluteMode::ToggleButton,
+            ..Default::default()
+        });
+        // When
+        let issues = mode.validate_for(ControlType::VirtualMulti);
+        // Then
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].code, ModeIssueCode::ToggleWithoutCurrentValue);
+    }
+
+    #[test]
+    fn default_settings() {
+        // Given
+        let mode: Mode<TestTransformation> = Mode::new(Default::default());
+        // When
+        // Then
+        assert!(mode
+            .validate_for(ControlType::AbsoluteContinuous)
+            .is_empty());
+        assert!(mode.validate_for(ControlType::Relative).is_empty());
+        assert!(mode
+            .validate_for(ControlType::AbsoluteDiscrete {
+                atomic_step_size: UnitValue::new(0.1),
+            })
+            .is_empty());
+    }
+}
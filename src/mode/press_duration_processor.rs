@@ -1,4 +1,4 @@
-use crate::{AbsoluteValue, FireMode, Interval};
+use crate::{AbsoluteValue, FireMode, Interval, UnitValue};
 use std::time::{Duration, Instant};
 
 #[derive(Clone, Debug)]
@@ -8,8 +8,11 @@ pub struct PressDurationProcessor {
     interval: Interval<Duration>,
     multi_press_span: Duration,
     turbo_rate: Duration,
+    debounce: Duration,
+    max_hold: Option<Duration>,
     // Runtime data (changes during usage)
     last_button_press: Option<ButtonPress>,
+    time_of_last_emitted_press: Option<Instant>,
 }
 
 #[derive(Clone, Debug)]
@@ -22,9 +25,9 @@ struct ButtonPress {
 }
 
 impl ButtonPress {
-    pub fn new(value: AbsoluteValue) -> Self {
+    pub fn new(value: AbsoluteValue, now: Instant) -> Self {
         Self {
-            time: Instant::now(),
+            time: now,
             value,
             time_of_last_turbo_fire: None,
             count: 1,
@@ -42,7 +45,10 @@ impl Default for PressDurationProcessor {
             interval: Interval::new(ZERO_DURATION, ZERO_DURATION),
             multi_press_span: Duration::from_millis(300),
             turbo_rate: ZERO_DURATION,
+            debounce: ZERO_DURATION,
+            max_hold: None,
             last_button_press: None,
+            time_of_last_emitted_press: None,
         }
     }
 }
@@ -52,11 +58,15 @@ impl PressDurationProcessor {
         mode: FireMode,
         interval: Interval<Duration>,
         turbo_rate: Duration,
+        debounce: Duration,
+        max_hold: Option<Duration>,
     ) -> PressDurationProcessor {
         PressDurationProcessor {
             fire_mode: mode,
             interval,
             turbo_rate,
+            debounce,
+            max_hold,
             ..Default::default()
         }
     }
@@ -65,16 +75,63 @@ impl PressDurationProcessor {
     /// `poll()`, regularly.
     pub fn wants_to_be_polled(&self) -> bool {
         // This must not depend on the button press state!
+        if self.max_hold.is_some() {
+            // Needed for detecting presses without release
+            return true;
+        }
         use FireMode::*;
         match self.fire_mode {
             AfterTimeout | AfterTimeoutKeepFiring | OnSinglePress => true,
-            WhenButtonReleased | OnDoublePress => false,
+            WhenButtonReleased | OnDoublePress | HoldToConfirm => false,
         }
     }
 
     pub fn process_press_or_release(
         &mut self,
         control_value: AbsoluteValue,
+    ) -> Option<AbsoluteValue> {
+        self.process_press_or_release_at(control_value, Instant::now())
+    }
+
+    /// Like `process_press_or_release()` but with an injected current time.
+    ///
+    /// If a debounce is set, button presses arriving within the debounce window of the previously
+    /// emitted press are dropped (e.g. to prevent chords from double-triggering).
+    pub fn process_press_or_release_at(
+        &mut self,
+        control_value: AbsoluteValue,
+        now: Instant,
+    ) -> Option<AbsoluteValue> {
+        if control_value.is_on() && self.is_within_debounce_window(now) {
+            return None;
+        }
+        let result = self.process_press_or_release_internal(control_value, now);
+        self.memorize_emitted_press(result, now);
+        result
+    }
+
+    fn is_within_debounce_window(&self, now: Instant) -> bool {
+        if self.debounce == ZERO_DURATION {
+            return false;
+        }
+        match self.time_of_last_emitted_press {
+            None => false,
+            Some(t) => now.saturating_duration_since(t) < self.debounce,
+        }
+    }
+
+    fn memorize_emitted_press(&mut self, result: Option<AbsoluteValue>, now: Instant) {
+        if let Some(v) = result {
+            if v.is_on() {
+                self.time_of_last_emitted_press = Some(now);
+            }
+        }
+    }
+
+    fn process_press_or_release_internal(
+        &mut self,
+        control_value: AbsoluteValue,
+        now: Instant,
     ) -> Option<AbsoluteValue> {
         let min = self.interval.min_val();
         let max = self.interval.max_val();
@@ -90,7 +147,7 @@ impl PressDurationProcessor {
                 if control_value.is_on() {
                     // This is a button press.
                     // Don't fire now because we don't know yet how long it will be pressed.
-                    self.last_button_press = Some(ButtonPress::new(control_value));
+                    self.last_button_press = Some(ButtonPress::new(control_value, now));
                     None
                 } else {
                     // Looks like a button release.
@@ -100,7 +157,10 @@ impl PressDurationProcessor {
                         None => None,
                         // Button has been pressed before.
                         Some(press) => {
-                            if self.interval.contains(press.time.elapsed()) {
+                            if self
+                                .interval
+                                .contains(now.saturating_duration_since(press.time))
+                            {
                                 // Duration within interval. Fire initial press value.
                                 Some(press.value)
                             } else {
@@ -118,7 +178,7 @@ impl PressDurationProcessor {
                 }
                 if control_value.is_on() {
                     // Button press
-                    self.last_button_press = Some(ButtonPress::new(control_value));
+                    self.last_button_press = Some(ButtonPress::new(control_value, now));
                     None
                 } else {
                     // Button release
@@ -129,10 +189,10 @@ impl PressDurationProcessor {
             FireMode::AfterTimeoutKeepFiring => {
                 if control_value.is_on() {
                     // Button press
-                    let mut button_press = ButtonPress::new(control_value);
+                    let mut button_press = ButtonPress::new(control_value, now);
                     let result = if min == ZERO_DURATION {
                         // No initial delay. Fire immediately and count as first turbo fire!
-                        button_press.time_of_last_turbo_fire = Some(Instant::now());
+                        button_press.time_of_last_turbo_fire = Some(now);
                         Some(control_value)
                     } else {
                         // Initial delay (wait for timeout).
@@ -152,10 +212,10 @@ impl PressDurationProcessor {
                     if let Some(press) = self.last_button_press.as_mut() {
                         // Must be more than single press already.
                         press.count += 1;
-                        press.time = Instant::now();
+                        press.time = now;
                     } else {
                         // First press
-                        self.last_button_press = Some(ButtonPress::new(control_value));
+                        self.last_button_press = Some(ButtonPress::new(control_value, now));
                     };
                     None
                 } else {
@@ -165,7 +225,7 @@ impl PressDurationProcessor {
                         if press.count != 1 {
                             return None;
                         }
-                        let elapsed = press.time.elapsed();
+                        let elapsed = now.saturating_duration_since(press.time);
                         if elapsed < self.multi_press_span {
                             press.released = true;
                             return None;
@@ -186,19 +246,19 @@ impl PressDurationProcessor {
                 if control_value.is_on() {
                     if let Some(press) = &self.last_button_press {
                         // Button was pressed before
-                        let (result, next_press) = if press.time.elapsed() <= self.multi_press_span
-                        {
-                            // Double press detected
-                            (Some(press.value), None)
-                        } else {
-                            // Previous press too long in past. Handle just like first press.
-                            (None, Some(ButtonPress::new(control_value)))
-                        };
+                        let (result, next_press) =
+                            if now.saturating_duration_since(press.time) <= self.multi_press_span {
+                                // Double press detected
+                                (Some(press.value), None)
+                            } else {
+                                // Previous press too long in past. Handle just like first press.
+                                (None, Some(ButtonPress::new(control_value, now)))
+                            };
                         self.last_button_press = next_press;
                         result
                     } else {
                         // First press
-                        self.last_button_press = Some(ButtonPress::new(control_value));
+                        self.last_button_press = Some(ButtonPress::new(control_value, now));
                         None
                     }
                 } else {
@@ -206,18 +266,83 @@ impl PressDurationProcessor {
                     None
                 }
             }
+            FireMode::HoldToConfirm => {
+                if control_value.is_on() {
+                    // Button press. Wait for release.
+                    self.last_button_press = Some(ButtonPress::new(control_value, now));
+                    None
+                } else {
+                    // Button release
+                    let press = self.last_button_press.take()?;
+                    let elapsed = now.saturating_duration_since(press.time);
+                    if elapsed < min {
+                        // Released too early. Cancel!
+                        return None;
+                    }
+                    if max != ZERO_DURATION && elapsed > max {
+                        // Released too late. Cancel, too!
+                        return None;
+                    }
+                    Some(press.value)
+                }
+            }
         }
     }
 
     /// Should be called regularly if `wants_to_be_polled()` returned `true` at initialization
     /// time.
     pub fn poll(&mut self) -> Option<AbsoluteValue> {
+        self.poll_at(Instant::now())
+    }
+
+    /// Like `poll()` but with an injected current time.
+    ///
+    /// If a maximum hold time is set and the button has been held longer than that (e.g. because
+    /// the release event got lost), this processes a release as if the button had been released
+    /// right now and forgets the press, so the processor can't get stuck.
+    pub fn poll_at(&mut self, now: Instant) -> Option<AbsoluteValue> {
+        if let Some(release) = self.overdue_release(now) {
+            let result = self.process_press_or_release_internal(release, now);
+            self.last_button_press = None;
+            self.memorize_emitted_press(result, now);
+            return result;
+        }
+        let result = self.poll_internal(now);
+        self.memorize_emitted_press(result, now);
+        result
+    }
+
+    /// Returns the release value to be processed if the current press exceeded the maximum hold
+    /// time.
+    fn overdue_release(&self, now: Instant) -> Option<AbsoluteValue> {
+        let max_hold = self.max_hold?;
+        if self.fire_mode == FireMode::OnDoublePress {
+            // Keeps the first press after release (waiting for the second one), so we can't know
+            // whether the button is still held. Releases don't have any effect anyway.
+            return None;
+        }
+        let press = self.last_button_press.as_ref()?;
+        if press.released || now.saturating_duration_since(press.time) < max_hold {
+            return None;
+        }
+        let release = match press.value {
+            AbsoluteValue::Continuous(_) => AbsoluteValue::Continuous(UnitValue::MIN),
+            AbsoluteValue::Discrete(f) => AbsoluteValue::Discrete(f.with_actual(0)),
+        };
+        Some(release)
+    }
+
+    fn poll_internal(&mut self, now: Instant) -> Option<AbsoluteValue> {
         match self.fire_mode {
-            FireMode::WhenButtonReleased | FireMode::OnDoublePress => None,
+            FireMode::WhenButtonReleased | FireMode::OnDoublePress | FireMode::HoldToConfirm => {
+                None
+            }
             FireMode::AfterTimeout => {
                 let fire_value = {
                     let last_button_press = self.last_button_press.as_ref()?;
-                    if last_button_press.time.elapsed() >= self.interval.min_val() {
+                    if now.saturating_duration_since(last_button_press.time)
+                        >= self.interval.min_val()
+                    {
                         Some(last_button_press.value)
                     } else {
                         None
@@ -232,17 +357,19 @@ impl PressDurationProcessor {
                 let last_button_press = self.last_button_press.as_mut()?;
                 if let Some(last_turbo) = last_button_press.time_of_last_turbo_fire {
                     // We are in turbo stage already.
-                    if last_turbo.elapsed() >= self.turbo_rate {
+                    if now.saturating_duration_since(last_turbo) >= self.turbo_rate {
                         // Subsequent turbo fire!
-                        last_button_press.time_of_last_turbo_fire = Some(Instant::now());
+                        last_button_press.time_of_last_turbo_fire = Some(now);
                         Some(last_button_press.value)
                     } else {
                         // Not yet time for next turbo fire.
                         None
                     }
-                } else if last_button_press.time.elapsed() >= self.interval.min_val() {
+                } else if now.saturating_duration_since(last_button_press.time)
+                    >= self.interval.min_val()
+                {
                     // We reached the initial delay. First turbo fire!
-                    last_button_press.time_of_last_turbo_fire = Some(Instant::now());
+                    last_button_press.time_of_last_turbo_fire = Some(now);
                     Some(last_button_press.value)
                 } else {
                     None
@@ -251,7 +378,7 @@ impl PressDurationProcessor {
             FireMode::OnSinglePress => {
                 let fire_value = {
                     let press = self.last_button_press.as_ref()?;
-                    let elapsed = press.time.elapsed();
+                    let elapsed = now.saturating_duration_since(press.time);
                     if elapsed < self.multi_press_span {
                         // Can't decide yet if this is a single press.
                         return None;
@@ -277,3 +404,239 @@ impl PressDurationProcessor {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn press() -> AbsoluteValue {
+        AbsoluteValue::Continuous(UnitValue::MAX)
+    }
+
+    fn release() -> AbsoluteValue {
+        AbsoluteValue::Continuous(UnitValue::MIN)
+    }
+
+    fn hold_to_confirm_processor() -> PressDurationProcessor {
+        PressDurationProcessor::new(
+            FireMode::HoldToConfirm,
+            Interval::new(Duration::from_millis(500), Duration::from_millis(2000)),
+            ZERO_DURATION,
+            ZERO_DURATION,
+            None,
+        )
+    }
+
+    fn debouncing_processor() -> PressDurationProcessor {
+        PressDurationProcessor::new(
+            FireMode::WhenButtonReleased,
+            Interval::new(ZERO_DURATION, ZERO_DURATION),
+            ZERO_DURATION,
+            Duration::from_millis(50),
+            None,
+        )
+    }
+
+    fn turbo_processor_with_max_hold() -> PressDurationProcessor {
+        PressDurationProcessor::new(
+            FireMode::AfterTimeoutKeepFiring,
+            Interval::new(Duration::from_millis(100), Duration::from_millis(100)),
+            Duration::from_millis(50),
+            ZERO_DURATION,
+            Some(Duration::from_millis(1000)),
+        )
+    }
+
+    #[test]
+    fn debounce_presses_within_window() {
+        // Given
+        let mut processor = debouncing_processor();
+        let start = Instant::now();
+        // When
+        let first_press_result = processor.process_press_or_release_at(press(), start);
+        let second_press_result =
+            processor.process_press_or_release_at(press(), start + Duration::from_millis(20));
+        // Then
+        assert_eq!(first_press_result, Some(press()));
+        assert_eq!(second_press_result, None);
+    }
+
+    #[test]
+    fn debounce_presses_outside_window() {
+        // Given
+        let mut processor = debouncing_processor();
+        let start = Instant::now();
+        // When
+        let first_press_result = processor.process_press_or_release_at(press(), start);
+        let release_result =
+            processor.process_press_or_release_at(release(), start + Duration::from_millis(10));
+        let second_press_result =
+            processor.process_press_or_release_at(press(), start + Duration::from_millis(80));
+        // Then
+        assert_eq!(first_press_result, Some(press()));
+        assert_eq!(release_result, Some(release()));
+        assert_eq!(second_press_result, Some(press()));
+    }
+
+    #[test]
+    fn hold_to_confirm_release_before_threshold() {
+        // Given
+        let mut processor = hold_to_confirm_processor();
+        let start = Instant::now();
+        // When
+        let press_result = processor.process_press_or_release_at(press(), start);
+        let poll_result = processor.poll_at(start + Duration::from_millis(200));
+        let release_result =
+            processor.process_press_or_release_at(release(), start + Duration::from_millis(300));
+        // Then
+        assert!(!processor.wants_to_be_polled());
+        assert_eq!(press_result, None);
+        assert_eq!(poll_result, None);
+        assert_eq!(release_result, None);
+    }
+
+    #[test]
+    fn hold_to_confirm_release_within_window() {
+        // Given
+        let mut processor = hold_to_confirm_processor();
+        let start = Instant::now();
+        // When
+        let press_result = processor.process_press_or_release_at(press(), start);
+        let poll_result = processor.poll_at(start + Duration::from_millis(700));
+        let release_result =
+            processor.process_press_or_release_at(release(), start + Duration::from_millis(1000));
+        // Then
+        assert_eq!(press_result, None);
+        assert_eq!(poll_result, None);
+        assert_eq!(release_result, Some(press()));
+    }
+
+    #[test]
+    fn hold_to_confirm_release_after_window() {
+        // Given
+        let mut processor = hold_to_confirm_processor();
+        let start = Instant::now();
+        // When
+        let press_result = processor.process_press_or_release_at(press(), start);
+        let release_result =
+            processor.process_press_or_release_at(release(), start + Duration::from_millis(2500));
+        // Then
+        assert_eq!(press_result, None);
+        assert_eq!(release_result, None);
+    }
+
+    #[test]
+    fn hold_to_confirm_release_without_press() {
+        // Given
+        let mut processor = hold_to_confirm_processor();
+        // When
+        let release_result = processor.process_press_or_release_at(release(), Instant::now());
+        // Then
+        assert_eq!(release_result, None);
+    }
+
+    #[test]
+    fn max_hold_auto_releases_hold_to_confirm() {
+        // Given
+        let mut processor = PressDurationProcessor::new(
+            FireMode::HoldToConfirm,
+            Interval::new(Duration::from_millis(500), Duration::from_millis(2000)),
+            ZERO_DURATION,
+            ZERO_DURATION,
+            Some(Duration::from_millis(1500)),
+        );
+        let start = Instant::now();
+        // When
+        let press_result = processor.process_press_or_release_at(press(), start);
+        let early_poll_result = processor.poll_at(start + Duration::from_millis(1000));
+        let overdue_poll_result = processor.poll_at(start + Duration::from_millis(1500));
+        let later_poll_result = processor.poll_at(start + Duration::from_millis(1600));
+        let late_release_result =
+            processor.process_press_or_release_at(release(), start + Duration::from_millis(1700));
+        // Then
+        assert!(processor.wants_to_be_polled());
+        assert_eq!(press_result, None);
+        assert_eq!(early_poll_result, None);
+        assert_eq!(overdue_poll_result, Some(press()));
+        assert_eq!(later_poll_result, None);
+        assert_eq!(late_release_result, None);
+    }
+
+    #[test]
+    fn max_hold_debounces_presses_after_auto_release() {
+        // Given
+        let mut processor = PressDurationProcessor::new(
+            FireMode::HoldToConfirm,
+            Interval::new(Duration::from_millis(500), Duration::from_millis(2000)),
+            ZERO_DURATION,
+            Duration::from_millis(500),
+            Some(Duration::from_millis(1500)),
+        );
+        let start = Instant::now();
+        // When
+        processor.process_press_or_release_at(press(), start);
+        let overdue_poll_result = processor.poll_at(start + Duration::from_millis(1500));
+        processor.process_press_or_release_at(press(), start + Duration::from_millis(1600));
+        let release_result =
+            processor.process_press_or_release_at(release(), start + Duration::from_millis(2200));
+        // Then
+        assert_eq!(overdue_poll_result, Some(press()));
+        // The second press was dropped because it was within the debounce window
+        assert_eq!(release_result, None);
+    }
+
+    #[test]
+    fn max_hold_keeps_double_press_waiting() {
+        // Given
+        let mut processor = PressDurationProcessor::new(
+            FireMode::OnDoublePress,
+            Interval::new(ZERO_DURATION, ZERO_DURATION),
+            ZERO_DURATION,
+            ZERO_DURATION,
+            Some(Duration::from_millis(200)),
+        );
+        let start = Instant::now();
+        // When
+        let first_press_result = processor.process_press_or_release_at(press(), start);
+        processor.process_press_or_release_at(release(), start + Duration::from_millis(50));
+        let poll_result = processor.poll_at(start + Duration::from_millis(250));
+        let second_press_result =
+            processor.process_press_or_release_at(press(), start + Duration::from_millis(280));
+        // Then
+        assert_eq!(first_press_result, None);
+        assert_eq!(poll_result, None);
+        assert_eq!(second_press_result, Some(press()));
+    }
+
+    #[test]
+    fn max_hold_stops_turbo() {
+        // Given
+        let mut processor = turbo_processor_with_max_hold();
+        let start = Instant::now();
+        // When
+        let press_result = processor.process_press_or_release_at(press(), start);
+        let first_fire = processor.poll_at(start + Duration::from_millis(100));
+        let turbo_fire = processor.poll_at(start + Duration::from_millis(950));
+        let overdue_poll_result = processor.poll_at(start + Duration::from_millis(1000));
+        let later_poll_result = processor.poll_at(start + Duration::from_millis(1100));
+        // Then
+        assert_eq!(press_result, None);
+        assert_eq!(first_fire, Some(press()));
+        assert_eq!(turbo_fire, Some(press()));
+        assert_eq!(overdue_poll_result, None);
+        assert_eq!(later_poll_result, None);
+    }
+
+    #[test]
+    fn max_hold_not_reached() {
+        // Given
+        let mut processor = turbo_processor_with_max_hold();
+        let start = Instant::now();
+        // When
+        processor.process_press_or_release_at(press(), start);
+        processor.poll_at(start + Duration::from_millis(100));
+        let turbo_fire = processor.poll_at(start + Duration::from_millis(999));
+        // Then
+        assert_eq!(turbo_fire, Some(press()));
+    }
+}
@@ -70,9 +70,85 @@ pub trait Target<'a> {
     /// In such cases, `None` should be returned so that the mode can handle this situation
     /// gracefully. Of course, some mode features won't work without knowing the current value,
     /// but others will still work.
+    ///
+    /// The mode queries the current value only if it actually needs it. In particular, it's never
+    /// queried when forwarding increments to targets which want them (`ControlType::Relative` and
+    /// `ControlType::VirtualMulti`) unless a target value sequence is used. So it's okay if this
+    /// is a bit more expensive.
     fn current_value(&self, context: Self::Context) -> Option<AbsoluteValue>;
 
+    /// Should return the control type of the target.
+    ///
+    /// This is queried on each control invocation, so it should be cheap.
     fn control_type(&self, context: Self::Context) -> ControlType;
+
+    /// Can format the given normalized value in the native unit of the target (e.g. "-6.00 dB").
+    ///
+    /// If available, textual feedback uses this instead of the default text value property.
+    /// Returns `None` by default.
+    fn format_value(&self, value: UnitValue) -> Option<String> {
+        let _ = value;
+        None
+    }
+}
+
+/// Makes it possible to pass trait objects (e.g. `&dyn Target`) where a target is expected.
+impl<'a, T: Target<'a> + ?Sized> Target<'a> for &T {
+    type Context = T::Context;
+
+    fn current_value(&self, context: Self::Context) -> Option<AbsoluteValue> {
+        (*self).current_value(context)
+    }
+
+    fn control_type(&self, context: Self::Context) -> ControlType {
+        (*self).control_type(context)
+    }
+
+    fn format_value(&self, value: UnitValue) -> Option<String> {
+        (*self).format_value(value)
+    }
+}
+
+/// Target interface of earlier versions, which didn't know about control types yet.
+///
+/// Wrap implementations in [`LegacyTarget`] in order to use them as [`Target`].
+pub trait LegacyTargetMethods {
+    /// Should return the current value of the target.
+    fn current_value(&self) -> Option<UnitValue>;
+
+    /// Should return the step size if the target is discrete.
+    fn step_size(&self) -> Option<UnitValue>;
+
+    /// Should return `true` if the target wants to be controlled via relative increments.
+    fn wants_increments(&self) -> bool;
+}
+
+/// Adapter which makes an old-style target usable as [`Target`].
+pub struct LegacyTarget<T>(pub T);
+
+impl<'a, T: LegacyTargetMethods> Target<'a> for LegacyTarget<T> {
+    type Context = ();
+
+    fn current_value(&self, _: ()) -> Option<AbsoluteValue> {
+        self.0.current_value().map(AbsoluteValue::Continuous)
+    }
+
+    fn control_type(&self, _: ()) -> ControlType {
+        derive_control_type(self.0.step_size(), self.0.wants_increments())
+    }
+}
+
+/// Derives the control type from the information provided by old-style targets.
+///
+/// Wanting increments takes precedence over the step size.
+pub fn derive_control_type(step_size: Option<UnitValue>, wants_increments: bool) -> ControlType {
+    if wants_increments {
+        return ControlType::Relative;
+    }
+    match step_size {
+        None => ControlType::AbsoluteContinuous,
+        Some(atomic_step_size) => ControlType::AbsoluteDiscrete { atomic_step_size },
+    }
 }
 
 /// Some standardized property keys.
@@ -126,3 +202,68 @@ pub mod target_prop_keys {
     /// - Project: Navigate within tracks → 0.7
     pub const NORMALIZED_VALUE: &str = "normalized_value";
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct OldStyleTarget {
+        step_size: Option<UnitValue>,
+        wants_increments: bool,
+    }
+
+    impl LegacyTargetMethods for OldStyleTarget {
+        fn current_value(&self) -> Option<UnitValue> {
+            Some(UnitValue::new(0.5))
+        }
+
+        fn step_size(&self) -> Option<UnitValue> {
+            self.step_size
+        }
+
+        fn wants_increments(&self) -> bool {
+            self.wants_increments
+        }
+    }
+
+    #[test]
+    fn derive_control_types() {
+        let step_size = UnitValue::new(0.1);
+        assert_eq!(
+            derive_control_type(None, false),
+            ControlType::AbsoluteContinuous
+        );
+        assert_eq!(
+            derive_control_type(Some(step_size), false),
+            ControlType::AbsoluteDiscrete {
+                atomic_step_size: step_size
+            }
+        );
+        assert_eq!(derive_control_type(None, true), ControlType::Relative);
+        assert_eq!(
+            derive_control_type(Some(step_size), true),
+            ControlType::Relative
+        );
+    }
+
+    #[test]
+    fn legacy_target() {
+        // Given
+        let target = LegacyTarget(OldStyleTarget {
+            step_size: Some(UnitValue::new(0.25)),
+            wants_increments: false,
+        });
+        // When
+        // Then
+        assert_eq!(
+            target.current_value(()),
+            Some(AbsoluteValue::Continuous(UnitValue::new(0.5)))
+        );
+        assert_eq!(
+            target.control_type(()),
+            ControlType::AbsoluteDiscrete {
+                atomic_step_size: UnitValue::new(0.25)
+            }
+        );
+    }
+}
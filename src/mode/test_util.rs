@@ -1,4 +1,4 @@
-use crate::{AbsoluteValue, ControlType, Target, Transformation};
+use crate::{AbsoluteValue, ControlType, Target, Transformation, TransformationError, UnitValue};
 
 pub struct TestTarget {
     pub current_value: Option<AbsoluteValue>,
@@ -17,13 +17,51 @@ impl<'a> Target<'a> for TestTarget {
     }
 }
 
+/// Target which must never be asked for its current value.
+pub struct NoCurrentValueTarget {
+    pub control_type: ControlType,
+}
+
+impl<'a> Target<'a> for NoCurrentValueTarget {
+    type Context = ();
+
+    fn current_value(&self, _: ()) -> Option<AbsoluteValue> {
+        panic!("current value queried although not necessary")
+    }
+
+    fn control_type(&self, _: ()) -> ControlType {
+        self.control_type
+    }
+}
+
+/// Target which formats its value as percentage.
+pub struct PercentageTarget {
+    pub current_value: Option<AbsoluteValue>,
+}
+
+impl<'a> Target<'a> for PercentageTarget {
+    type Context = ();
+
+    fn current_value(&self, _: ()) -> Option<AbsoluteValue> {
+        self.current_value
+    }
+
+    fn control_type(&self, _: ()) -> ControlType {
+        ControlType::AbsoluteContinuous
+    }
+
+    fn format_value(&self, value: UnitValue) -> Option<String> {
+        Some(format!("{}%", (value.get() * 100.0).round()))
+    }
+}
+
 pub struct TestTransformation {
-    transformer: Box<dyn Fn(f64) -> Result<f64, &'static str>>,
+    transformer: Box<dyn Fn(f64) -> Result<f64, TransformationError>>,
 }
 
 impl TestTransformation {
     pub fn new(
-        transformer: impl Fn(f64) -> Result<f64, &'static str> + 'static,
+        transformer: impl Fn(f64) -> Result<f64, TransformationError> + 'static,
     ) -> TestTransformation {
         Self {
             transformer: Box::new(transformer),
@@ -34,7 +72,7 @@ impl TestTransformation {
 impl Transformation for TestTransformation {
     type AdditionalInput = ();
 
-    fn transform(&self, input_value: f64, _: f64, _: ()) -> Result<f64, &'static str> {
+    fn transform(&self, input_value: f64, _: f64, _: ()) -> Result<f64, TransformationError> {
         (self.transformer)(input_value)
     }
 }
@@ -1,4 +1,48 @@
 use crate::{Fraction, UnitValue};
+use derive_more::Display;
+use std::borrow::Cow;
+
+/// Error which can occur when applying a transformation.
+///
+/// The kind of error decides what the mode does with the value in question. Errors which concern
+/// the transformation as a whole (`Compile` and `Custom`) make the mode fall back to the
+/// untransformed value. Errors which concern the particular evaluation (`Runtime` and `Undefined`)
+/// make the mode skip the value.
+#[derive(Clone, Eq, PartialEq, Debug, Display)]
+pub enum TransformationError {
+    /// The transformation couldn't be compiled (e.g. syntax error in expression).
+    #[display(fmt = "transformation couldn't be compiled")]
+    Compile,
+    /// The evaluation failed at runtime.
+    #[display(fmt = "transformation failed at runtime")]
+    Runtime,
+    /// The evaluation succeeded but the result is undefined for the given input (e.g. NaN).
+    #[display(fmt = "transformation result is undefined")]
+    Undefined,
+    /// Any other error.
+    #[display(fmt = "{}", _0)]
+    Custom(Cow<'static, str>),
+}
+
+impl TransformationError {
+    /// Returns whether the value in question should be skipped (instead of falling back to the
+    /// untransformed value).
+    pub fn skips_value(&self) -> bool {
+        use TransformationError::*;
+        match self {
+            Compile | Custom(_) => false,
+            Runtime | Undefined => true,
+        }
+    }
+}
+
+impl std::error::Error for TransformationError {}
+
+impl From<&'static str> for TransformationError {
+    fn from(msg: &'static str) -> Self {
+        TransformationError::Custom(msg.into())
+    }
+}
 
 /// Represents an arbitrary transformation from one unit value into another one, intended to be
 /// implemented by using some form of expression language.
@@ -14,14 +58,14 @@ pub trait Transformation {
         input_value: f64,
         output_value: f64,
         additional_input: Self::AdditionalInput,
-    ) -> Result<f64, &'static str>;
+    ) -> Result<f64, TransformationError>;
 
     fn transform_continuous(
         &self,
         input_value: UnitValue,
         output_value: UnitValue,
         additional_input: Self::AdditionalInput,
-    ) -> Result<UnitValue, &'static str> {
+    ) -> Result<UnitValue, TransformationError> {
         let res = self.transform(input_value.get(), output_value.get(), additional_input)?;
         Ok(UnitValue::new_clamped(res))
     }
@@ -31,7 +75,7 @@ pub trait Transformation {
         input_value: Fraction,
         output_value: Fraction,
         additional_input: Self::AdditionalInput,
-    ) -> Result<Fraction, &'static str> {
+    ) -> Result<Fraction, TransformationError> {
         let res = self.transform(
             input_value.actual() as _,
             output_value.actual() as _,
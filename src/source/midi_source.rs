@@ -2192,6 +2192,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn control_change_value_2_center() {
+        // Given
+        let source = TestMidiSource::ControlChangeValue {
+            channel: Some(ch(1)),
+            controller_number: Some(cn(64)),
+            custom_character: SourceCharacter::Encoder2,
+        };
+        // When
+        // Then
+        // 64 means "no movement", so it must not reach the mode as zero increment
+        assert_eq!(source.control(&plain(control_change(1, 64, 64))), None);
+        assert_eq!(
+            source.control(&plain(control_change(1, 64, 65))),
+            Some(rel(1))
+        );
+        assert_eq!(
+            source.control(&plain(control_change(1, 64, 63))),
+            Some(rel(-1))
+        );
+    }
+
     #[test]
     fn program_change_number_1() {
         // Given
@@ -1,4 +1,4 @@
-use crate::{AbsoluteValue, ControlValue, UnitValue};
+use crate::{AbsoluteValue, UnitValue};
 use approx::AbsDiffEq;
 
 impl AbsDiffEq for UnitValue {
@@ -13,23 +13,6 @@ impl AbsDiffEq for UnitValue {
     }
 }
 
-impl AbsDiffEq for ControlValue {
-    type Epsilon = f64;
-
-    fn default_epsilon() -> f64 {
-        f64::EPSILON
-    }
-
-    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
-        match (self, other) {
-            (ControlValue::AbsoluteContinuous(v1), ControlValue::AbsoluteContinuous(v2)) => {
-                v1.abs_diff_eq(v2, epsilon)
-            }
-            _ => self == other,
-        }
-    }
-}
-
 impl AbsDiffEq for AbsoluteValue {
     type Epsilon = f64;
 